@@ -0,0 +1,107 @@
+use anyhow::Context;
+use anyhow::Result;
+use codex_config::ConfigLayerSource;
+use codex_config::ConfigManifestEntry;
+use codex_config::config_layer_manifest;
+use codex_core::config::Config;
+use codex_utils_cli::CliConfigOverrides;
+
+/// Subcommands:
+/// - `manifest` — list the config layers that feed into a session, with content hashes
+#[derive(Debug, clap::Parser)]
+pub struct ConfigCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ConfigSubcommand {
+    /// Print each config layer with the SHA-256 of its backing file, noting
+    /// layers that are disabled.
+    Manifest(ManifestArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ManifestArgs {
+    /// Output the manifest as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl ConfigCli {
+    pub async fn run(self) -> Result<()> {
+        let ConfigCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        match subcommand {
+            ConfigSubcommand::Manifest(args) => {
+                run_manifest(&config_overrides, args).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn run_manifest(config_overrides: &CliConfigOverrides, args: ManifestArgs) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = Config::load_with_cli_overrides(overrides)
+        .await
+        .context("failed to load configuration")?;
+    let manifest = config_layer_manifest(&config.config_layer_stack);
+
+    if args.json {
+        let output = serde_json::to_string_pretty(&manifest)?;
+        println!("{output}");
+        return Ok(());
+    }
+
+    let rows: Vec<[String; 3]> = manifest.iter().map(manifest_row).collect();
+    let source_width = rows.iter().map(|[source, ..]| source.len()).max();
+    let hash_width = rows.iter().map(|[_, hash, _]| hash.len()).max();
+    let (Some(source_width), Some(hash_width)) = (source_width, hash_width) else {
+        println!("No config layers found.");
+        return Ok(());
+    };
+    for [source, hash, file] in rows {
+        println!("{source:<source_width$}  {hash:<hash_width$}  {file}");
+    }
+
+    Ok(())
+}
+
+/// Renders an entry as `[source, hash, file]`. Symbolic sources have no file,
+/// so they show the hash of their parsed contents instead. Disabled layers
+/// carry their reason after the file.
+fn manifest_row(entry: &ConfigManifestEntry) -> [String; 3] {
+    let source = match &entry.source {
+        ConfigLayerSource::Mdm { domain, key } => format!("mdm:{domain}:{key}"),
+        ConfigLayerSource::System { .. } => "system".to_string(),
+        ConfigLayerSource::User { .. } => "user".to_string(),
+        ConfigLayerSource::Project { .. } => "project".to_string(),
+        ConfigLayerSource::SessionFlags => "session-flags".to_string(),
+        ConfigLayerSource::LegacyManagedConfigTomlFromFile { .. } => {
+            "legacy-managed-config".to_string()
+        }
+        ConfigLayerSource::LegacyManagedConfigTomlFromMdm => {
+            "legacy-managed-config:mdm".to_string()
+        }
+    };
+    let (hash, file) = match (&entry.file, &entry.sha256) {
+        (Some(file), Some(sha256)) => (sha256.clone(), file.display().to_string()),
+        (Some(file), None) => ("(not read)".to_string(), file.display().to_string()),
+        (None, _) => (entry.version.clone(), "-".to_string()),
+    };
+    let file = match &entry.disabled_reason {
+        Some(reason) => format!("{file}  (disabled: {reason})"),
+        None => file,
+    };
+    [source, hash, file]
+}
@@ -39,6 +39,7 @@ use supports_color::Stream;
 
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod app_cmd;
+mod config_cmd;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod desktop_app;
 mod marketplace_cmd;
@@ -47,6 +48,7 @@ mod responses_cmd;
 #[cfg(not(windows))]
 mod wsl_paths;
 
+use crate::config_cmd::ConfigCli;
 use crate::marketplace_cmd::MarketplaceCli;
 use crate::mcp_cmd::McpCli;
 use crate::responses_cmd::ResponsesCommand;
@@ -176,6 +178,9 @@ enum Subcommand {
 
     /// Inspect feature flags.
     Features(FeaturesCli),
+
+    /// Inspect the config layers that make up the effective configuration.
+    Config(ConfigCli),
 }
 
 #[derive(Debug, Parser)]
@@ -1220,6 +1225,16 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
                 disable_feature_in_config(&interactive, &feature).await?;
             }
         },
+        Some(Subcommand::Config(mut config_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "config",
+            )?;
+            // Propagate any root-level config overrides (e.g. `-c key=value`).
+            prepend_config_flags(&mut config_cli.config_overrides, root_config_overrides);
+            config_cli.run().await?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+#[tokio::test]
+async fn config_manifest_json_hashes_user_config() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let cwd = TempDir::new()?;
+    std::fs::write(codex_home.path().join("config.toml"), "model = \"o3\"\n")?;
+    // CODEX_HOME is canonicalized when config is loaded.
+    let config_path = codex_home.path().canonicalize()?.join("config.toml");
+
+    let mut cmd = codex_command(codex_home.path())?;
+    let output = cmd
+        .current_dir(cwd.path())
+        .args(["config", "manifest", "--json"])
+        .output()?;
+    assert!(output.status.success());
+
+    let manifest: Vec<JsonValue> = serde_json::from_slice(&output.stdout)?;
+    let user_entry = manifest
+        .into_iter()
+        .find(|entry| entry["source"]["type"] == "user")
+        .expect("user layer should be listed");
+    assert_eq!(
+        json!({
+            "file": user_entry["file"],
+            "sha256": user_entry["sha256"],
+        }),
+        json!({
+            "file": config_path,
+            "sha256": "sha256:e0e4d37c81f763b18f09b0440c01714f80ed7f82c8bf7104e406cccb4c720e96",
+        })
+    );
+
+    Ok(())
+}
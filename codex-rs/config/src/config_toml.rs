@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::ConfigLayerSourceKind;
 use crate::HookEventsToml;
 use crate::permissions_toml::PermissionsToml;
 use crate::profile_toml::ConfigProfile;
@@ -370,6 +371,12 @@ pub struct ConfigToml {
     #[serde(default)]
     pub project_root_markers: Option<Vec<String>>,
 
+    /// Config layer sources to skip when assembling the effective config.
+    /// Only honored in the system, managed, and user config, whose lists are
+    /// combined; project config and `-c` overrides drop the key unvalidated.
+    #[serde(default)]
+    pub disabled_config_layers: Option<Vec<ConfigLayerSourceKind>>,
+
     /// When `true`, checks for Codex updates on startup and surfaces update prompts.
     /// Set to `false` only if your Codex updates are centrally managed.
     /// Defaults to `true`.
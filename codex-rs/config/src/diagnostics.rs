@@ -191,7 +191,10 @@ where
     None
 }
 
-fn config_path_for_layer(layer: &ConfigLayerEntry, config_toml_file: &str) -> Option<PathBuf> {
+pub(crate) fn config_path_for_layer(
+    layer: &ConfigLayerEntry,
+    config_toml_file: &str,
+) -> Option<PathBuf> {
     match &layer.name {
         ConfigLayerSource::System { file } => Some(file.to_path_buf()),
         ConfigLayerSource::User { file } => Some(file.to_path_buf()),
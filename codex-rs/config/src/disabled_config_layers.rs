@@ -0,0 +1,122 @@
+use std::io;
+
+use crate::ConfigLayerEntry;
+use crate::ConfigLayerStack;
+use crate::ConfigLayerStackOrdering;
+use codex_app_server_protocol::ConfigLayerSource;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use toml::Value as TomlValue;
+
+const DISABLED_CONFIG_LAYERS_KEY: &str = "disabled_config_layers";
+
+/// Reason recorded on layers turned off by `disabled_config_layers`.
+pub const DISABLED_CONFIG_LAYER_REASON: &str =
+    "this config layer source is listed in `disabled_config_layers`";
+
+/// Config layer sources that can be turned off with `disabled_config_layers`.
+/// Managed sources (MDM, system, legacy managed config) are not listed so they
+/// cannot be disabled.
+///
+/// `user` is only honored from system or managed config. `session_flags`
+/// covers `-c`/`--config` overrides but not session thread config supplied by
+/// the host.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigLayerSourceKind {
+    User,
+    Project,
+    SessionFlags,
+}
+
+impl ConfigLayerSourceKind {
+    fn matches(self, source: &ConfigLayerSource) -> bool {
+        match source {
+            ConfigLayerSource::User { .. } => self == Self::User,
+            ConfigLayerSource::Project { .. } => self == Self::Project,
+            ConfigLayerSource::SessionFlags => self == Self::SessionFlags,
+            ConfigLayerSource::Mdm { .. }
+            | ConfigLayerSource::System { .. }
+            | ConfigLayerSource::LegacyManagedConfigTomlFromFile { .. }
+            | ConfigLayerSource::LegacyManagedConfigTomlFromMdm => false,
+        }
+    }
+}
+
+/// Reads `disabled_config_layers` from a merged `config.toml` [toml::Value].
+///
+/// Returns an empty list when the key is unset, and an error when it is not an
+/// array of known [ConfigLayerSourceKind] names.
+pub fn disabled_config_layers_from_config(
+    config: &TomlValue,
+) -> io::Result<Vec<ConfigLayerSourceKind>> {
+    let Some(value) = config
+        .as_table()
+        .and_then(|table| table.get(DISABLED_CONFIG_LAYERS_KEY))
+    else {
+        return Ok(Vec::new());
+    };
+    value.clone().try_into().map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{DISABLED_CONFIG_LAYERS_KEY} must be an array of \"user\", \"project\", or \"session_flags\": {err}"
+            ),
+        )
+    })
+}
+
+/// Removes `disabled_config_layers` from the config of a layer that may not
+/// set it (project and `-c` overrides), so the value is ignored there instead
+/// of being validated when the effective config is deserialized.
+pub fn strip_disabled_config_layers(config: &mut TomlValue) {
+    if let Some(table) = config.as_table_mut() {
+        table.remove(DISABLED_CONFIG_LAYERS_KEY);
+    }
+}
+
+/// Returns the sources disabled in an already-loaded `stack`: the union of
+/// the `disabled_config_layers` lists in its enabled system, managed, and user
+/// layers, matching what the loader applied. Lists the loader would have
+/// rejected are skipped.
+pub fn disabled_config_layers_for_stack(stack: &ConfigLayerStack) -> Vec<ConfigLayerSourceKind> {
+    let mut disabled = Vec::new();
+    for layer in stack.get_layers(
+        ConfigLayerStackOrdering::LowestPrecedenceFirst,
+        /*include_disabled*/ false,
+    ) {
+        if matches!(
+            layer.name,
+            ConfigLayerSource::Project { .. } | ConfigLayerSource::SessionFlags
+        ) {
+            continue;
+        }
+        for kind in disabled_config_layers_from_config(&layer.config).unwrap_or_default() {
+            if !disabled.contains(&kind) {
+                disabled.push(kind);
+            }
+        }
+    }
+    disabled
+}
+
+/// Marks every layer whose source is listed in `disabled` as disabled, keeping
+/// it in the stack so the disabled set stays visible to callers that list
+/// layers with `include_disabled`. Layers that are already disabled keep their
+/// original reason.
+pub fn disable_config_layer_sources(
+    layers: &mut [ConfigLayerEntry],
+    disabled: &[ConfigLayerSourceKind],
+) {
+    for layer in layers {
+        if layer.disabled_reason.is_none() && disabled.iter().any(|kind| kind.matches(&layer.name))
+        {
+            layer.disabled_reason = Some(DISABLED_CONFIG_LAYER_REASON.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "disabled_config_layers_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+use super::*;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use pretty_assertions::assert_eq;
+
+fn empty_table() -> TomlValue {
+    TomlValue::Table(toml::map::Map::new())
+}
+
+#[test]
+fn reads_disabled_config_layers() {
+    let config: TomlValue = toml::from_str(r#"disabled_config_layers = ["project"]"#)
+        .expect("config TOML should parse");
+
+    let disabled = disabled_config_layers_from_config(&config).expect("valid list");
+
+    assert_eq!(disabled, vec![ConfigLayerSourceKind::Project]);
+}
+
+#[test]
+fn rejects_managed_sources_in_disabled_config_layers() {
+    let config: TomlValue =
+        toml::from_str(r#"disabled_config_layers = ["system"]"#).expect("config TOML should parse");
+
+    let err = disabled_config_layers_from_config(&config).expect_err("system is not disableable");
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn disables_only_listed_sources() {
+    let user_file = AbsolutePathBuf::from_absolute_path(std::env::temp_dir().join("config.toml"))
+        .expect("absolute path");
+    let dot_codex_folder = AbsolutePathBuf::from_absolute_path(std::env::temp_dir().join(".codex"))
+        .expect("absolute path");
+    let user = ConfigLayerEntry::new(ConfigLayerSource::User { file: user_file }, empty_table());
+    let project = ConfigLayerEntry::new(
+        ConfigLayerSource::Project { dot_codex_folder },
+        empty_table(),
+    );
+    let session_flags = ConfigLayerEntry::new_disabled(
+        ConfigLayerSource::SessionFlags,
+        empty_table(),
+        "already disabled",
+    );
+    let mut layers = vec![user.clone(), project.clone(), session_flags.clone()];
+
+    disable_config_layer_sources(
+        &mut layers,
+        &[
+            ConfigLayerSourceKind::Project,
+            ConfigLayerSourceKind::SessionFlags,
+        ],
+    );
+
+    assert_eq!(
+        layers,
+        vec![
+            user,
+            ConfigLayerEntry {
+                disabled_reason: Some(
+                    "this config layer source is listed in `disabled_config_layers`".to_string()
+                ),
+                ..project
+            },
+            session_flags,
+        ]
+    );
+}
+
+#[test]
+fn stack_disabled_config_layers_ignore_project_and_session_flags_lists() {
+    let system_file = AbsolutePathBuf::from_absolute_path(std::env::temp_dir().join("system.toml"))
+        .expect("absolute path");
+    let user_file = AbsolutePathBuf::from_absolute_path(std::env::temp_dir().join("config.toml"))
+        .expect("absolute path");
+    let dot_codex_folder = AbsolutePathBuf::from_absolute_path(std::env::temp_dir().join(".codex"))
+        .expect("absolute path");
+    let stack = ConfigLayerStack::new(
+        vec![
+            ConfigLayerEntry::new(
+                ConfigLayerSource::System { file: system_file },
+                toml::from_str(r#"disabled_config_layers = ["user"]"#).expect("valid TOML"),
+            ),
+            ConfigLayerEntry::new(
+                ConfigLayerSource::User { file: user_file },
+                toml::from_str(r#"disabled_config_layers = ["project"]"#).expect("valid TOML"),
+            ),
+            ConfigLayerEntry::new(
+                ConfigLayerSource::Project { dot_codex_folder },
+                toml::from_str(r#"disabled_config_layers = ["session_flags"]"#)
+                    .expect("valid TOML"),
+            ),
+        ],
+        crate::ConfigRequirements::default(),
+        crate::ConfigRequirementsToml::default(),
+    )
+    .expect("stack should be valid");
+
+    assert_eq!(
+        disabled_config_layers_for_stack(&stack),
+        vec![ConfigLayerSourceKind::User, ConfigLayerSourceKind::Project]
+    );
+}
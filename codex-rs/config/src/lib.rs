@@ -3,10 +3,12 @@ mod config_requirements;
 pub mod config_toml;
 mod constraint;
 mod diagnostics;
+mod disabled_config_layers;
 mod fingerprint;
 mod hook_config;
 mod host_name;
 mod key_aliases;
+mod manifest;
 mod marketplace_edit;
 mod mcp_edit;
 mod mcp_types;
@@ -73,6 +75,12 @@ pub use diagnostics::first_layer_config_error_from_entries;
 pub use diagnostics::format_config_error;
 pub use diagnostics::format_config_error_with_source;
 pub use diagnostics::io_error_from_config_error;
+pub use disabled_config_layers::ConfigLayerSourceKind;
+pub use disabled_config_layers::DISABLED_CONFIG_LAYER_REASON;
+pub use disabled_config_layers::disable_config_layer_sources;
+pub use disabled_config_layers::disabled_config_layers_for_stack;
+pub use disabled_config_layers::disabled_config_layers_from_config;
+pub use disabled_config_layers::strip_disabled_config_layers;
 pub use fingerprint::version_for_toml;
 pub use hook_config::HookEventsToml;
 pub use hook_config::HookHandlerConfig;
@@ -80,6 +88,8 @@ pub use hook_config::HooksFile;
 pub use hook_config::ManagedHooksRequirementsToml;
 pub use hook_config::MatcherGroup;
 pub use host_name::host_name;
+pub use manifest::ConfigManifestEntry;
+pub use manifest::config_layer_manifest;
 pub use marketplace_edit::MarketplaceConfigUpdate;
 pub use marketplace_edit::RemoveMarketplaceConfigOutcome;
 pub use marketplace_edit::record_user_marketplace;
@@ -0,0 +1,66 @@
+//! Content-hash manifest of the config layers that feed into a session, so a
+//! run can be tied to an exact config state and drift between runs detected.
+
+use crate::CONFIG_TOML_FILE;
+use crate::ConfigLayerStack;
+use crate::ConfigLayerStackOrdering;
+use crate::diagnostics::config_path_for_layer;
+use crate::fingerprint::sha256_for_bytes;
+use codex_app_server_protocol::ConfigLayerSource;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// A single config layer as recorded in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigManifestEntry {
+    pub source: ConfigLayerSource,
+    /// File backing this layer. `None` for symbolic sources such as session
+    /// flags and MDM, which have no file on disk.
+    pub file: Option<PathBuf>,
+    /// `sha256:<hex>` of the file contents the loader read for this layer,
+    /// or `None` when the layer is not file-backed or its file was not read
+    /// (it does not exist, or the layer was skipped as disabled).
+    pub sha256: Option<String>,
+    /// Hash of the layer's parsed contents, as reported by
+    /// [crate::ConfigLayerEntry::version]. Present for every source, so
+    /// symbolic layers can still be compared across runs.
+    pub version: String,
+    /// Why the layer does not contribute to the effective config, e.g. an
+    /// untrusted project or a source listed in `disabled_config_layers`.
+    pub disabled_reason: Option<String>,
+}
+
+/// Returns one manifest entry per layer in `stack`, including disabled ones,
+/// ordered from lowest to highest precedence.
+///
+/// File hashes are computed from [crate::ConfigLayerEntry::raw_toml], i.e. the
+/// bytes the loader actually parsed, so they describe the config the session
+/// was built from even if a file changes afterwards.
+pub fn config_layer_manifest(stack: &ConfigLayerStack) -> Vec<ConfigManifestEntry> {
+    stack
+        .get_layers(
+            ConfigLayerStackOrdering::LowestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .map(|layer| {
+            let file = config_path_for_layer(layer, CONFIG_TOML_FILE);
+            let sha256 = file
+                .as_ref()
+                .and(layer.raw_toml())
+                .map(|contents| sha256_for_bytes(contents.as_bytes()));
+            ConfigManifestEntry {
+                source: layer.name.clone(),
+                file,
+                sha256,
+                version: layer.version.clone(),
+                disabled_reason: layer.disabled_reason.clone(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "manifest_tests.rs"]
+mod tests;
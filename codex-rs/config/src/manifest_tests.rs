@@ -0,0 +1,115 @@
+use super::*;
+use crate::ConfigLayerEntry;
+use crate::ConfigRequirements;
+use crate::ConfigRequirementsToml;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use toml::Value as TomlValue;
+
+#[test]
+fn manifest_hashes_loaded_file_contents_and_records_symbolic_layers() {
+    let tmp = TempDir::new().expect("tempdir");
+    let user_file = tmp.path().join(CONFIG_TOML_FILE);
+    let user_contents = "model = \"o3\"\n";
+    // The file on disk no longer matches what was loaded; the manifest must
+    // hash the loaded contents.
+    std::fs::write(&user_file, "model = \"gpt-5\"\n").expect("write user config");
+    let project_folder = tmp.path().join("repo").join(".codex");
+    std::fs::create_dir_all(&project_folder).expect("create project folder");
+
+    let user_layer = ConfigLayerEntry::new(
+        ConfigLayerSource::User {
+            file: AbsolutePathBuf::from_absolute_path(&user_file).expect("absolute path"),
+        },
+        toml::from_str(user_contents).expect("user config should parse"),
+    )
+    .with_raw_toml(user_contents.to_string());
+    let project_layer = ConfigLayerEntry::new(
+        ConfigLayerSource::Project {
+            dot_codex_folder: AbsolutePathBuf::from_absolute_path(&project_folder)
+                .expect("absolute path"),
+        },
+        TomlValue::Table(toml::map::Map::new()),
+    );
+    let session_layer = ConfigLayerEntry::new(
+        ConfigLayerSource::SessionFlags,
+        toml::from_str("model_reasoning_effort = \"high\"").expect("flags should parse"),
+    );
+    let stack = ConfigLayerStack::new(
+        vec![
+            user_layer.clone(),
+            project_layer.clone(),
+            session_layer.clone(),
+        ],
+        ConfigRequirements::default(),
+        ConfigRequirementsToml::default(),
+    )
+    .expect("stack should be valid");
+
+    let manifest = config_layer_manifest(&stack);
+
+    assert_eq!(
+        manifest,
+        vec![
+            ConfigManifestEntry {
+                source: user_layer.name,
+                file: Some(user_file),
+                sha256: Some(
+                    "sha256:e0e4d37c81f763b18f09b0440c01714f80ed7f82c8bf7104e406cccb4c720e96"
+                        .to_string()
+                ),
+                version: user_layer.version,
+                disabled_reason: None,
+            },
+            ConfigManifestEntry {
+                source: project_layer.name,
+                file: Some(project_folder.join(CONFIG_TOML_FILE)),
+                sha256: None,
+                version: project_layer.version,
+                disabled_reason: None,
+            },
+            ConfigManifestEntry {
+                source: ConfigLayerSource::SessionFlags,
+                file: None,
+                sha256: None,
+                version: session_layer.version,
+                disabled_reason: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn manifest_reports_disabled_layers() {
+    let tmp = TempDir::new().expect("tempdir");
+    let project_folder = tmp.path().join(".codex");
+    std::fs::create_dir_all(&project_folder).expect("create project folder");
+    let disabled = ConfigLayerEntry::new_disabled(
+        ConfigLayerSource::Project {
+            dot_codex_folder: AbsolutePathBuf::from_absolute_path(&project_folder)
+                .expect("absolute path"),
+        },
+        TomlValue::Table(toml::map::Map::new()),
+        "project is untrusted",
+    );
+    let stack = ConfigLayerStack::new(
+        vec![disabled.clone()],
+        ConfigRequirements::default(),
+        ConfigRequirementsToml::default(),
+    )
+    .expect("stack should be valid");
+
+    let manifest = config_layer_manifest(&stack);
+
+    assert_eq!(
+        manifest,
+        vec![ConfigManifestEntry {
+            source: disabled.name,
+            file: Some(project_folder.join(CONFIG_TOML_FILE)),
+            sha256: None,
+            version: disabled.version,
+            disabled_reason: Some("project is untrusted".to_string()),
+        }]
+    );
+}
@@ -100,6 +100,13 @@ impl ConfigLayerEntry {
         }
     }
 
+    /// Attaches the text this layer was parsed from, e.g. the contents of its
+    /// `config.toml` as read by the loader.
+    pub fn with_raw_toml(mut self, raw_toml: String) -> Self {
+        self.raw_toml = Some(raw_toml);
+        self
+    }
+
     pub fn is_disabled(&self) -> bool {
         self.disabled_reason.is_some()
     }
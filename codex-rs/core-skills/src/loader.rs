@@ -8,9 +8,12 @@ use crate::model::SkillPolicy;
 use crate::model::SkillToolDependency;
 use crate::system::system_cache_root_dir;
 use codex_app_server_protocol::ConfigLayerSource;
+use codex_config::ConfigLayerSourceKind;
 use codex_config::ConfigLayerStack;
 use codex_config::ConfigLayerStackOrdering;
+use codex_config::DISABLED_CONFIG_LAYER_REASON;
 use codex_config::default_project_root_markers;
+use codex_config::disabled_config_layers_for_stack;
 use codex_config::merge_toml_values;
 use codex_config::project_root_markers_from_config;
 use codex_exec_server::ExecutorFileSystem;
@@ -265,6 +268,11 @@ fn skill_roots_from_layer_stack_inner(
         ConfigLayerStackOrdering::HighestPrecedenceFirst,
         /*include_disabled*/ true,
     ) {
+        // Untrusted project layers still contribute skill roots, but sources
+        // turned off with `disabled_config_layers` are ignored entirely.
+        if layer.disabled_reason.as_deref() == Some(DISABLED_CONFIG_LAYER_REASON) {
+            continue;
+        }
         let Some(config_folder) = layer.config_folder() else {
             continue;
         };
@@ -332,6 +340,11 @@ async fn repo_agents_skill_roots(
     let Some(fs) = fs else {
         return Vec::new();
     };
+    if disabled_config_layers_for_stack(config_layer_stack)
+        .contains(&ConfigLayerSourceKind::Project)
+    {
+        return Vec::new();
+    }
     let project_root_markers = project_root_markers_from_stack(config_layer_stack);
     let project_root = find_project_root(fs.as_ref(), cwd, &project_root_markers).await;
     let dirs = dirs_between_project_root_and_cwd(cwd, &project_root);
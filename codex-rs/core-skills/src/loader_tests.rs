@@ -271,6 +271,72 @@ async fn skill_roots_from_layer_stack_includes_disabled_project_layers() -> anyh
     Ok(())
 }
 
+#[tokio::test]
+async fn skill_roots_from_layer_stack_skips_project_layers_disabled_by_config() -> anyhow::Result<()>
+{
+    let tmp = tempfile::tempdir()?;
+
+    let home_folder = tmp.path().join("home");
+    let user_folder = home_folder.join("codex");
+    fs::create_dir_all(&user_folder)?;
+
+    let project_root = tmp.path().join("repo");
+    let dot_codex = project_root.join(REPO_ROOT_CONFIG_DIR_NAME);
+    fs::create_dir_all(&dot_codex)?;
+    fs::create_dir_all(project_root.join(AGENTS_DIR_NAME).join(SKILLS_DIR_NAME))?;
+
+    let layers = vec![
+        ConfigLayerEntry::new(
+            ConfigLayerSource::User {
+                file: user_folder.join("config.toml").abs(),
+            },
+            toml::from_str(r#"disabled_config_layers = ["project"]"#)?,
+        ),
+        ConfigLayerEntry::new_disabled(
+            ConfigLayerSource::Project {
+                dot_codex_folder: dot_codex.abs(),
+            },
+            TomlValue::Table(toml::map::Map::new()),
+            codex_config::DISABLED_CONFIG_LAYER_REASON,
+        ),
+    ];
+    let stack = ConfigLayerStack::new(
+        layers,
+        ConfigRequirements::default(),
+        ConfigRequirementsToml::default(),
+    )?;
+
+    let home_folder_abs = home_folder.abs();
+    let project_root_abs = project_root.abs();
+    let got = skill_roots_from_layer_stack(
+        Arc::clone(&LOCAL_FS),
+        &stack,
+        &project_root_abs,
+        Some(&home_folder_abs),
+    )
+    .await
+    .into_iter()
+    .map(|root| (root.scope, root.path.to_path_buf()))
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        got,
+        vec![
+            (SkillScope::User, user_folder.join("skills")),
+            (
+                SkillScope::User,
+                home_folder.join(AGENTS_DIR_NAME).join(SKILLS_DIR_NAME)
+            ),
+            (
+                SkillScope::System,
+                user_folder.join("skills").join(".system")
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn loads_skills_from_home_agents_dir_for_user_scope() -> anyhow::Result<()> {
     let tmp = tempfile::tempdir()?;
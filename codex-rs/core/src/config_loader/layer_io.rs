@@ -19,6 +19,7 @@ const CODEX_MANAGED_CONFIG_SYSTEM_PATH: &str = "/etc/codex/managed_config.toml";
 pub(super) struct MangedConfigFromFile {
     pub managed_config: TomlValue,
     pub file: AbsolutePathBuf,
+    pub raw_toml: String,
 }
 
 #[derive(Debug, Clone)]
@@ -60,9 +61,10 @@ pub(super) async fn load_config_layers_internal(
     let managed_config =
         read_config_from_path(fs, &managed_config_path, /*log_missing_as_info*/ false)
             .await?
-            .map(|managed_config| MangedConfigFromFile {
+            .map(|(managed_config, raw_toml)| MangedConfigFromFile {
                 managed_config,
                 file: managed_config_path.clone(),
+                raw_toml,
             });
 
     #[cfg(target_os = "macos")]
@@ -93,10 +95,10 @@ pub(super) async fn read_config_from_path(
     fs: &dyn ExecutorFileSystem,
     path: &AbsolutePathBuf,
     log_missing_as_info: bool,
-) -> io::Result<Option<TomlValue>> {
+) -> io::Result<Option<(TomlValue, String)>> {
     match fs.read_file_text(path, /*sandbox*/ None).await {
         Ok(contents) => match toml::from_str::<TomlValue>(&contents) {
-            Ok(value) => Ok(Some(value)),
+            Ok(value) => Ok(Some((value, contents))),
             Err(err) => {
                 tracing::error!("Failed to parse {}: {err}", path.as_path().display());
                 let config_error = config_error_from_toml(path.as_path(), &contents, err.clone());
@@ -8,11 +8,16 @@ mod tests;
 use crate::config_loader::layer_io::LoadedConfigLayers;
 use codex_app_server_protocol::ConfigLayerSource;
 use codex_config::CONFIG_TOML_FILE;
+use codex_config::ConfigLayerSourceKind;
 use codex_config::ConfigRequirementsWithSources;
+use codex_config::DISABLED_CONFIG_LAYER_REASON;
 use codex_config::ThreadConfigContext;
 use codex_config::ThreadConfigLoader;
 use codex_config::config_toml::ConfigToml;
 use codex_config::config_toml::ProjectConfig;
+use codex_config::disable_config_layer_sources;
+use codex_config::disabled_config_layers_from_config;
+use codex_config::strip_disabled_config_layers;
 use codex_exec_server::ExecutorFileSystem;
 use codex_git_utils::resolve_root_git_project_for_trust;
 use codex_protocol::config_types::ApprovalsReviewer;
@@ -197,7 +202,8 @@ pub async fn load_config_layers_state(
     let cli_overrides_layer = if cli_overrides.is_empty() {
         None
     } else {
-        let cli_overrides_layer = build_cli_overrides_layer(cli_overrides);
+        let mut cli_overrides_layer = build_cli_overrides_layer(cli_overrides);
+        strip_disabled_config_layers(&mut cli_overrides_layer);
         let base_dir = cwd
             .as_ref()
             .map(AbsolutePathBuf::as_path)
@@ -221,13 +227,35 @@ pub async fn load_config_layers_state(
             )
         })
         .await?;
+
+    // `disabled_config_layers` is unioned across the system and managed
+    // sources, then extended by the user layer, rather than merged like other
+    // keys: a lower-precedence list must not be able to replace an admin's.
+    let mut disabled_config_layers =
+        disabled_config_layers_for_layer(&system_layer.config, std::slice::from_ref(&system_layer))
+            .await?;
+    let managed_configs = [
+        loaded_config_layers
+            .managed_config
+            .as_ref()
+            .map(|config| &config.managed_config),
+        loaded_config_layers
+            .managed_config_from_mdm
+            .as_ref()
+            .map(|config| &config.managed_config),
+    ];
+    for managed_config in managed_configs.into_iter().flatten() {
+        let kinds = disabled_config_layers_for_layer(managed_config, &[]).await?;
+        extend_disabled_config_layers(&mut disabled_config_layers, kinds);
+    }
     layers.push(system_layer);
 
     // Add a layer for $CODEX_HOME/config.toml so folder-derived resources such
-    // as rules/ can still be discovered. When user config is ignored, preserve
-    // the layer metadata without reading config.toml.
+    // as rules/ can still be discovered. When user config is ignored or
+    // disabled, preserve the layer metadata without reading config.toml.
+    let user_layer_disabled = disabled_config_layers.contains(&ConfigLayerSourceKind::User);
     let user_file = AbsolutePathBuf::resolve_path_against_base(CONFIG_TOML_FILE, codex_home);
-    let user_layer = if ignore_user_config {
+    let user_layer = if ignore_user_config || user_layer_disabled {
         ConfigLayerEntry::new(
             ConfigLayerSource::User {
                 file: user_file.clone(),
@@ -245,14 +273,41 @@ pub async fn load_config_layers_state(
         })
         .await?
     };
+    let user_disabled_config_layers =
+        disabled_config_layers_for_layer(&user_layer.config, std::slice::from_ref(&user_layer))
+            .await?;
+    if user_disabled_config_layers.contains(&ConfigLayerSourceKind::User) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "`user` in `disabled_config_layers` is only honored in system or managed config, not in {}",
+                user_file.as_path().display()
+            ),
+        ));
+    }
+    extend_disabled_config_layers(&mut disabled_config_layers, user_disabled_config_layers);
     layers.push(user_layer);
 
+    // Project trust is granted in the user config.toml, so keep honoring it
+    // when the rest of that file is disabled.
+    let disabled_user_project_trust = if user_layer_disabled && !ignore_user_config {
+        project_trust_entries_from_file(fs, &user_file).await
+    } else {
+        None
+    };
+
     if let Some(cwd) = cwd {
         let mut merged_so_far = TomlValue::Table(toml::map::Map::new());
         for layer in &layers {
             merge_toml_values(&mut merged_so_far, &layer.config);
         }
-        if let Some(cli_overrides_layer) = cli_overrides_layer.as_ref() {
+        if let Some(project_trust) = disabled_user_project_trust.as_ref() {
+            merge_toml_values(&mut merged_so_far, project_trust);
+        }
+        // Disabled `-c` overrides must not steer project discovery or trust.
+        if let Some(cli_overrides_layer) = cli_overrides_layer.as_ref()
+            && !disabled_config_layers.contains(&ConfigLayerSourceKind::SessionFlags)
+        {
             merge_toml_values(&mut merged_so_far, cli_overrides_layer);
         }
 
@@ -301,6 +356,7 @@ pub async fn load_config_layers_state(
             &project_trust_context.project_root,
             &project_trust_context,
             codex_home,
+            disabled_config_layers.contains(&ConfigLayerSourceKind::Project),
         )
         .await?;
         layers.extend(project_layers);
@@ -314,6 +370,11 @@ pub async fn load_config_layers_state(
         ));
     }
 
+    // Apply `disabled_config_layers` before adding thread config: host-supplied
+    // session thread config also uses `ConfigLayerSource::SessionFlags`, but
+    // `session_flags` only covers `-c`/`--config` overrides.
+    disable_config_layer_sources(&mut layers, &disabled_config_layers);
+
     for thread_config_layer in thread_config_layers {
         insert_layer_by_precedence(&mut layers, thread_config_layer);
     }
@@ -339,10 +400,13 @@ pub async fn load_config_layers_state(
         })?;
         let managed_config =
             resolve_relative_paths_in_config_toml(config.managed_config, managed_parent)?;
-        layers.push(ConfigLayerEntry::new(
-            ConfigLayerSource::LegacyManagedConfigTomlFromFile { file: config.file },
-            managed_config,
-        ));
+        layers.push(
+            ConfigLayerEntry::new(
+                ConfigLayerSource::LegacyManagedConfigTomlFromFile { file: config.file },
+                managed_config,
+            )
+            .with_raw_toml(config.raw_toml),
+        );
     }
     if let Some(config) = managed_config_from_mdm {
         // As a general rule, config from MDM should _not_ include relative
@@ -367,6 +431,63 @@ pub async fn load_config_layers_state(
     .with_user_and_project_exec_policy_rules_ignored(ignore_user_and_project_exec_policy_rules))
 }
 
+/// Reads `disabled_config_layers` from a single layer's config, reporting a
+/// malformed value against the first invalid file in `layers` when there is
+/// one.
+async fn disabled_config_layers_for_layer(
+    config: &TomlValue,
+    layers: &[ConfigLayerEntry],
+) -> io::Result<Vec<ConfigLayerSourceKind>> {
+    match disabled_config_layers_from_config(config) {
+        Ok(disabled) => Ok(disabled),
+        Err(err) => {
+            if let Some(config_error) = first_layer_config_error_from_entries(layers).await {
+                return Err(io_error_from_config_error(
+                    io::ErrorKind::InvalidData,
+                    config_error,
+                    /*source*/ None,
+                ));
+            }
+            Err(err)
+        }
+    }
+}
+
+/// Reads only the `[projects]` trust entries from `config_file`. A missing,
+/// unreadable, or malformed file yields no entries rather than an error,
+/// since the file's layer is disabled.
+async fn project_trust_entries_from_file(
+    fs: &dyn ExecutorFileSystem,
+    config_file: &AbsolutePathBuf,
+) -> Option<TomlValue> {
+    let contents = fs
+        .read_file_text(config_file, /*sandbox*/ None)
+        .await
+        .ok()?;
+    let config: TomlValue = toml::from_str(&contents).ok()?;
+    let projects = config.get("projects")?.clone();
+    let project_trust = TomlValue::Table(toml::map::Map::from_iter([(
+        "projects".to_string(),
+        projects,
+    )]));
+    project_trust
+        .clone()
+        .try_into::<ProjectTrustConfigToml>()
+        .ok()?;
+    Some(project_trust)
+}
+
+fn extend_disabled_config_layers(
+    disabled: &mut Vec<ConfigLayerSourceKind>,
+    kinds: Vec<ConfigLayerSourceKind>,
+) {
+    for kind in kinds {
+        if !disabled.contains(&kind) {
+            disabled.push(kind);
+        }
+    }
+}
+
 fn insert_layer_by_precedence(layers: &mut Vec<ConfigLayerEntry>, layer: ConfigLayerEntry) {
     match layers
         .iter()
@@ -389,6 +510,7 @@ async fn load_config_toml_for_required_layer(
     toml_file: &AbsolutePathBuf,
     create_entry: impl FnOnce(TomlValue) -> ConfigLayerEntry,
 ) -> io::Result<ConfigLayerEntry> {
+    let mut raw_toml = None;
     let toml_value = match fs.read_file_text(toml_file, /*sandbox*/ None).await {
         Ok(contents) => {
             let config: TomlValue = toml::from_str(&contents).map_err(|err| {
@@ -405,6 +527,7 @@ async fn load_config_toml_for_required_layer(
                     ),
                 )
             })?;
+            raw_toml = Some(contents);
             resolve_relative_paths_in_config_toml(config, config_parent)
         }
         Err(e) => {
@@ -422,7 +545,11 @@ async fn load_config_toml_for_required_layer(
         }
     }?;
 
-    Ok(create_entry(toml_value))
+    let entry = create_entry(toml_value);
+    Ok(match raw_toml {
+        Some(raw_toml) => entry.with_raw_toml(raw_toml),
+        None => entry,
+    })
 }
 
 /// If available, apply requirements from the platform system
@@ -933,6 +1060,7 @@ async fn load_project_layers(
     project_root: &AbsolutePathBuf,
     trust_context: &ProjectTrustContext,
     codex_home: &Path,
+    project_layers_disabled: bool,
 ) -> io::Result<Vec<ConfigLayerEntry>> {
     let codex_home_abs = AbsolutePathBuf::from_absolute_path(codex_home)?;
     let codex_home_normalized =
@@ -971,10 +1099,20 @@ async fn load_project_layers(
         if dot_codex_abs == codex_home_abs || dot_codex_normalized == codex_home_normalized {
             continue;
         }
+        if project_layers_disabled {
+            // Record the folder without reading config.toml, so a malformed
+            // file in a disabled project cannot fail the load.
+            layers.push(project_layer_entry(
+                &dot_codex_abs,
+                TomlValue::Table(toml::map::Map::new()),
+                Some(DISABLED_CONFIG_LAYER_REASON.to_string()),
+            ));
+            continue;
+        }
         let config_file = dot_codex_abs.join(CONFIG_TOML_FILE);
         match fs.read_file_text(&config_file, /*sandbox*/ None).await {
             Ok(contents) => {
-                let config: TomlValue = match toml::from_str(&contents) {
+                let mut config: TomlValue = match toml::from_str(&contents) {
                     Ok(config) => config,
                     Err(e) => {
                         if decision.is_trusted() {
@@ -994,9 +1132,11 @@ async fn load_project_layers(
                         continue;
                     }
                 };
+                strip_disabled_config_layers(&mut config);
                 let config =
                     resolve_relative_paths_in_config_toml(config, dot_codex_abs.as_path())?;
-                let entry = project_layer_entry(&dot_codex_abs, config, disabled_reason.clone());
+                let entry = project_layer_entry(&dot_codex_abs, config, disabled_reason.clone())
+                    .with_raw_toml(contents);
                 layers.push(entry);
             }
             Err(err) => {
@@ -1527,7 +1527,7 @@ async fn codex_home_within_project_tree_is_not_double_loaded() -> std::io::Resul
                 dot_codex_folder: AbsolutePathBuf::from_absolute_path(&nested_dot_codex)?,
             },
             config: child_config.clone(),
-            raw_toml: None,
+            raw_toml: Some("foo = \"child\"\n".to_string()),
             version: version_for_toml(&child_config),
             disabled_reason: None,
         }],
@@ -1649,6 +1649,481 @@ async fn project_layers_disabled_when_untrusted_or_unknown() -> std::io::Result<
     Ok(())
 }
 
+#[tokio::test]
+async fn disabled_config_layers_in_user_config_disables_trusted_project_layers()
+-> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    tokio::fs::create_dir_all(project_root.join(".codex")).await?;
+    tokio::fs::write(
+        project_root.join(".codex").join(CONFIG_TOML_FILE),
+        "foo = \"project\"\n",
+    )
+    .await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(
+        &codex_home,
+        &project_root,
+        TrustLevel::Trusted,
+        /*project_root_markers*/ None,
+    )
+    .await?;
+    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_contents = tokio::fs::read_to_string(&config_path).await?;
+    tokio::fs::write(
+        &config_path,
+        format!("foo = \"user\"\ndisabled_config_layers = [\"project\"]\n{config_contents}"),
+    )
+    .await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&project_root)?),
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let project_disabled_reasons: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::HighestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| matches!(layer.name, super::ConfigLayerSource::Project { .. }))
+        .map(|layer| layer.disabled_reason.clone())
+        .collect();
+    assert_eq!(
+        project_disabled_reasons,
+        vec![Some(
+            "this config layer source is listed in `disabled_config_layers`".to_string()
+        )]
+    );
+    assert_eq!(
+        layers.effective_config().get("foo"),
+        Some(&TomlValue::String("user".to_string()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_config_layers_in_user_config_cannot_clear_managed_list() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    tokio::fs::create_dir_all(project_root.join(".codex")).await?;
+    tokio::fs::write(
+        project_root.join(".codex").join(CONFIG_TOML_FILE),
+        "foo = \"project\"\n",
+    )
+    .await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(
+        &codex_home,
+        &project_root,
+        TrustLevel::Trusted,
+        /*project_root_markers*/ None,
+    )
+    .await?;
+    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_contents = tokio::fs::read_to_string(&config_path).await?;
+    tokio::fs::write(
+        &config_path,
+        format!("foo = \"user\"\ndisabled_config_layers = []\n{config_contents}"),
+    )
+    .await?;
+    let managed_path = tmp.path().join("managed_config.toml");
+    tokio::fs::write(&managed_path, "disabled_config_layers = [\"project\"]\n").await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&project_root)?),
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::with_managed_config_path_for_tests(managed_path),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let project_disabled_reasons: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::HighestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| matches!(layer.name, super::ConfigLayerSource::Project { .. }))
+        .map(|layer| layer.disabled_reason.clone())
+        .collect();
+    assert_eq!(
+        project_disabled_reasons,
+        vec![Some(
+            "this config layer source is listed in `disabled_config_layers`".to_string()
+        )]
+    );
+    assert_eq!(
+        layers.effective_config().get("foo"),
+        Some(&TomlValue::String("user".to_string()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_project_layers_skip_malformed_project_config() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    let dot_codex = project_root.join(".codex");
+    tokio::fs::create_dir_all(&dot_codex).await?;
+    tokio::fs::write(dot_codex.join(CONFIG_TOML_FILE), "foo = [").await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(
+        &codex_home,
+        &project_root,
+        TrustLevel::Trusted,
+        /*project_root_markers*/ None,
+    )
+    .await?;
+    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_contents = tokio::fs::read_to_string(&config_path).await?;
+    tokio::fs::write(
+        &config_path,
+        format!("disabled_config_layers = [\"project\"]\n{config_contents}"),
+    )
+    .await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&project_root)?),
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let project_layers: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::HighestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| matches!(layer.name, super::ConfigLayerSource::Project { .. }))
+        .cloned()
+        .collect();
+    assert_eq!(
+        project_layers,
+        vec![ConfigLayerEntry::new_disabled(
+            super::ConfigLayerSource::Project {
+                dot_codex_folder: AbsolutePathBuf::from_absolute_path(&dot_codex)?,
+            },
+            TomlValue::Table(toml::map::Map::new()),
+            "this config layer source is listed in `disabled_config_layers`",
+        )]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_session_flags_do_not_affect_project_discovery() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let repo = tmp.path().join("repo");
+    let child = repo.join("child");
+    tokio::fs::create_dir_all(repo.join(".codex")).await?;
+    tokio::fs::create_dir_all(child.join(".codex")).await?;
+    tokio::fs::write(repo.join("root_marker"), "").await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    tokio::fs::write(
+        codex_home.join(CONFIG_TOML_FILE),
+        "disabled_config_layers = [\"session_flags\"]\n",
+    )
+    .await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&child)?),
+        &[(
+            "project_root_markers".to_string(),
+            TomlValue::Array(vec![TomlValue::String("root_marker".to_string())]),
+        )],
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let project_folders: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::LowestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter_map(|layer| match &layer.name {
+            super::ConfigLayerSource::Project { dot_codex_folder } => {
+                Some(dot_codex_folder.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        project_folders,
+        vec![AbsolutePathBuf::from_absolute_path(child.join(".codex"))?]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_session_flags_keep_thread_config_layers() -> anyhow::Result<()> {
+    let tmp = tempdir()?;
+    tokio::fs::write(
+        tmp.path().join(CONFIG_TOML_FILE),
+        "disabled_config_layers = [\"session_flags\"]\n",
+    )
+    .await?;
+    let cwd_dir = tmp.path().join("project");
+    tokio::fs::create_dir_all(&cwd_dir).await?;
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        tmp.path(),
+        Some(AbsolutePathBuf::from_absolute_path(&cwd_dir)?),
+        &[("features.plugins".to_string(), TomlValue::Boolean(true))],
+        LoaderOverrides::without_managed_config_for_tests(),
+        CloudRequirementsLoader::default(),
+        &StaticThreadConfigLoader::new(vec![ThreadConfigSource::Session(SessionThreadConfig {
+            features: BTreeMap::from([("plugins".to_string(), false)]),
+            ..Default::default()
+        })]),
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let session_flags_disabled_reasons: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::LowestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| layer.name == super::ConfigLayerSource::SessionFlags)
+        .map(|layer| layer.disabled_reason.clone())
+        .collect();
+    assert_eq!(
+        session_flags_disabled_reasons,
+        vec![
+            Some("this config layer source is listed in `disabled_config_layers`".to_string()),
+            None,
+        ]
+    );
+    assert_eq!(
+        layers
+            .effective_config()
+            .get("features")
+            .and_then(TomlValue::as_table)
+            .and_then(|features| features.get("plugins")),
+        Some(&TomlValue::Boolean(false))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn managed_config_can_disable_user_layer() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    tokio::fs::write(codex_home.join(CONFIG_TOML_FILE), "foo = [").await?;
+    let managed_path = tmp.path().join("managed_config.toml");
+    tokio::fs::write(&managed_path, "disabled_config_layers = [\"user\"]\n").await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        /*cwd*/ None,
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::with_managed_config_path_for_tests(managed_path),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let user_layers: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::LowestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| matches!(layer.name, super::ConfigLayerSource::User { .. }))
+        .cloned()
+        .collect();
+    assert_eq!(
+        user_layers,
+        vec![ConfigLayerEntry::new_disabled(
+            super::ConfigLayerSource::User {
+                file: AbsolutePathBuf::resolve_path_against_base(CONFIG_TOML_FILE, &codex_home),
+            },
+            TomlValue::Table(toml::map::Map::new()),
+            "this config layer source is listed in `disabled_config_layers`",
+        )]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_user_layer_keeps_project_trust() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    tokio::fs::create_dir_all(project_root.join(".codex")).await?;
+    tokio::fs::write(
+        project_root.join(".codex").join(CONFIG_TOML_FILE),
+        "foo = \"project\"\n",
+    )
+    .await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(
+        &codex_home,
+        &project_root,
+        TrustLevel::Trusted,
+        /*project_root_markers*/ None,
+    )
+    .await?;
+    let managed_path = tmp.path().join("managed_config.toml");
+    tokio::fs::write(&managed_path, "disabled_config_layers = [\"user\"]\n").await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&project_root)?),
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::with_managed_config_path_for_tests(managed_path),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    let disabled_reasons: Vec<_> = layers
+        .get_layers(
+            super::ConfigLayerStackOrdering::LowestPrecedenceFirst,
+            /*include_disabled*/ true,
+        )
+        .into_iter()
+        .filter(|layer| {
+            matches!(
+                layer.name,
+                super::ConfigLayerSource::User { .. } | super::ConfigLayerSource::Project { .. }
+            )
+        })
+        .map(|layer| layer.disabled_reason.clone())
+        .collect();
+    assert_eq!(
+        disabled_reasons,
+        vec![
+            Some("this config layer source is listed in `disabled_config_layers`".to_string()),
+            None,
+        ]
+    );
+    assert_eq!(
+        layers.effective_config().get("foo"),
+        Some(&TomlValue::String("project".to_string()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_config_cannot_disable_user_layer() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    tokio::fs::write(
+        tmp.path().join(CONFIG_TOML_FILE),
+        "disabled_config_layers = [\"user\"]\n",
+    )
+    .await?;
+
+    let err = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        tmp.path(),
+        /*cwd*/ None,
+        &[] as &[(String, TomlValue)],
+        LoaderOverrides::without_managed_config_for_tests(),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await
+    .expect_err("user config must not disable itself");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disabled_config_layers_in_project_and_cli_config_is_ignored() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let project_root = tmp.path().join("project");
+    tokio::fs::create_dir_all(project_root.join(".codex")).await?;
+    tokio::fs::write(
+        project_root.join(".codex").join(CONFIG_TOML_FILE),
+        "disabled_config_layers = [\"session_flags\", \"system\"]\n",
+    )
+    .await?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+    make_config_for_test(
+        &codex_home,
+        &project_root,
+        TrustLevel::Trusted,
+        /*project_root_markers*/ None,
+    )
+    .await?;
+
+    let layers = load_config_layers_state(
+        LOCAL_FS.as_ref(),
+        &codex_home,
+        Some(AbsolutePathBuf::from_absolute_path(&project_root)?),
+        &[
+            ("foo".to_string(), TomlValue::String("cli".to_string())),
+            (
+                "disabled_config_layers".to_string(),
+                TomlValue::Array(vec![TomlValue::String("system".to_string())]),
+            ),
+        ],
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+        &codex_config::NoopThreadConfigLoader,
+        /*host_name*/ None,
+    )
+    .await?;
+
+    assert_eq!(
+        layers.effective_config().get("foo"),
+        Some(&TomlValue::String("cli".to_string()))
+    );
+    assert_eq!(
+        layers.effective_config().get("disabled_config_layers"),
+        None
+    );
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn project_trust_does_not_match_configured_alias_for_canonical_cwd() -> std::io::Result<()> {